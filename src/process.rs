@@ -1,20 +1,25 @@
+use std::cmp;
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::Error as IOError;
-use std::io::Read;
+use std::io::{Read, Seek, SeekFrom};
 use std::option::Option;
 use std::result::Result;
-use std::sync::mpsc::{channel, Sender};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
 
 use jsonrpc_core;
-use reqwest;
 use serde_json;
 use serde_json::Value;
 use subprocess::{Exec, Popen, PopenError, Redirection};
+use ws;
 
 use super::rpc::types::NodeStatus;
 
+#[derive(Debug)]
 pub enum Error {
     EnvParseError,
     AlreadyRunning,
@@ -23,6 +28,8 @@ pub enum Error {
     IO(IOError),
     // This error caused when sending HTTP request to the codechain
     CodeChainRPC(String),
+    // A CallRPC/CallRPCBatch did not get a response within `rpc_timeout`.
+    RPCTimeout,
 }
 
 impl From<PopenError> for Error {
@@ -37,15 +44,431 @@ impl From<IOError> for Error {
     }
 }
 
+// How to launch the CodeChain node: `cargo run` against a source checkout
+// for development, or a released binary for production where no Rust
+// toolchain is present.
+pub enum ExecutionMode {
+    CargoRun,
+    Binary(String),
+}
+
 pub struct ProcessOption {
     pub codechain_dir: String,
     pub log_file_path: String,
+    // Maximum number of reconnect attempts when the RPC transport cannot be
+    // established, with exponential backoff between attempts.
+    pub rpc_max_retries: usize,
+    // Hard per-call timeout applied while waiting for an RPC response.
+    pub rpc_timeout: Duration,
+    // Default RPC port, used unless `run`'s `args` override it with a
+    // `--jsonrpc-port`/`--port` flag.
+    pub rpc_port: u16,
+    pub execution_mode: ExecutionMode,
+    // How often the background status poller refreshes the cached chain
+    // status while the node is running.
+    pub status_poll_interval: Duration,
+}
+
+/// Chain status cached by the background poller, refreshed every
+/// `status_poll_interval` while the node is running.
+#[derive(Clone)]
+pub struct ChainStatus {
+    pub best_block_number: u64,
+    pub peer_count: usize,
+    // Whether the node reports it is still catching up to the network,
+    // per `chain_getSyncing`.
+    pub syncing: bool,
+}
+
+/// `GetStatus`'s result: whether the process is alive, and - while it is -
+/// the most recently cached chain status. `chain_status` is `None` until
+/// the poller's first successful round, or if the process isn't running,
+/// or if RPC has been unreachable since the node started.
+pub struct NodeStatusSnapshot {
+    pub status: NodeStatus,
+    pub chain_status: Option<ChainStatus>,
+}
+
+/// Polls a handful of cheap RPCs on an interval and caches the result so
+/// `GetStatus` doesn't have to block on the node for every status check.
+struct Poller {
+    stop: Arc<AtomicBool>,
+    handle: thread::JoinHandle<()>,
+}
+
+impl Poller {
+    fn spawn(
+        url: String,
+        max_retries: usize,
+        rpc_timeout: Duration,
+        poll_interval: Duration,
+        chain_status: Arc<Mutex<Option<ChainStatus>>>,
+    ) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+
+        let handle = thread::Builder::new()
+            .name("process-status-poller".to_string())
+            .spawn(move || {
+                let mut transport: Option<Transport> = None;
+
+                while !thread_stop.load(Ordering::SeqCst) {
+                    if transport.as_ref().map_or(true, |transport: &Transport| !transport.is_alive()) {
+                        match Transport::connect(&url, max_retries) {
+                            Ok(new_transport) => transport = Some(new_transport),
+                            Err(err) => {
+                                cwarn!("Status poller failed to connect to CodeChain RPC: {:?}", err);
+                                thread::sleep(poll_interval);
+                                continue
+                            }
+                        }
+                    }
+                    let transport = transport.as_ref().expect("Just ensured it is Some");
+
+                    let calls = vec![
+                        ("chain_getBestBlockNumber".to_string(), vec![]),
+                        ("net_getPeerCount".to_string(), vec![]),
+                        ("chain_getSyncing".to_string(), vec![]),
+                    ];
+                    match transport.call_batch(calls, rpc_timeout) {
+                        Ok(results) => {
+                            let best_block_number = results.get(0).and_then(Value::as_u64);
+                            let peer_count = results.get(1).and_then(Value::as_u64);
+                            // `chain_getSyncing` is the node's own notion of whether it is
+                            // still catching up, so it doesn't false-positive on every
+                            // normally-produced block the way comparing consecutive
+                            // `best_block_number` polls would.
+                            let syncing = results.get(2).and_then(Value::as_bool).unwrap_or(false);
+                            if let (Some(best_block_number), Some(peer_count)) = (best_block_number, peer_count) {
+                                *chain_status.lock().expect("Lock should not be poisoned") = Some(ChainStatus {
+                                    best_block_number,
+                                    peer_count: peer_count as usize,
+                                    syncing,
+                                });
+                            }
+                        }
+                        Err(err) => {
+                            cwarn!("Failed to poll CodeChain status: {:?}", err);
+                        }
+                    }
+                    thread::sleep(poll_interval);
+                }
+            })
+            .expect("Should success running process-status-poller thread");
+
+        Self {
+            stop,
+            handle,
+        }
+    }
+
+    fn stop(self) {
+        self.stop.store(true, Ordering::SeqCst);
+        let _ = self.handle.join();
+    }
 }
 
 pub struct Process {
     option: ProcessOption,
     // first element is CodeChain second element is `tee` command
     child: Option<Vec<Popen>>,
+    transport: Option<Transport>,
+    // Byte offset up to which `log_file_path` has been delivered via
+    // `GetLog`. Only `GetLog` advances this; each `FollowLog` call copies it
+    // once as its own starting point and tracks progress locally from then
+    // on, so concurrent followers (or a `GetLog` racing a follower) can't
+    // race over, and desync, the same offset/carry state.
+    log_offset: Arc<Mutex<u64>>,
+    // Port the currently running CodeChain actually bound, resolved from
+    // `run`'s `args` or `option.rpc_port` if the args didn't specify one.
+    rpc_port: u16,
+    chain_status: Arc<Mutex<Option<ChainStatus>>>,
+    poller: Option<Poller>,
+}
+
+type PendingRequests = Arc<Mutex<HashMap<jsonrpc_core::Id, Sender<Result<Value, Error>>>>>;
+type Subscriptions = Arc<Mutex<HashMap<String, Vec<Sender<Value>>>>>;
+
+/// A persistent WebSocket connection to a CodeChain node's RPC endpoint.
+///
+/// A single background thread owns the socket and demultiplexes incoming
+/// frames: responses are matched to their waiting caller by id, while
+/// server-pushed notifications are fanned out to whoever owns the
+/// subscription id the notification names.
+pub struct Transport {
+    out: ws::Sender,
+    next_id: AtomicUsize,
+    pending_requests: PendingRequests,
+    subscriptions: Subscriptions,
+    // Flipped to false once the socket is known to have closed, so callers
+    // can tell a cached `Transport` apart from a live one instead of
+    // reusing a dead connection forever.
+    alive: Arc<AtomicBool>,
+}
+
+struct TransportHandler {
+    out: ws::Sender,
+    pending_requests: PendingRequests,
+    subscriptions: Subscriptions,
+    alive: Arc<AtomicBool>,
+}
+
+impl ws::Handler for TransportHandler {
+    fn on_message(&mut self, msg: ws::Message) -> ws::Result<()> {
+        let text = msg.into_text().unwrap_or_default();
+
+        if let Ok(output) = serde_json::from_str::<jsonrpc_core::Output>(&text) {
+            let id = match &output {
+                jsonrpc_core::Output::Success(success) => success.id.clone(),
+                jsonrpc_core::Output::Failure(failure) => failure.id.clone(),
+            };
+            if let Some(callback) = self.pending_requests.lock().expect("Lock should not be poisoned").remove(&id) {
+                let _ = callback.send(Transport::output_into_result(output));
+            }
+            return Ok(())
+        }
+
+        if let Ok(outputs) = serde_json::from_str::<Vec<jsonrpc_core::Output>>(&text) {
+            let mut pending_requests = self.pending_requests.lock().expect("Lock should not be poisoned");
+            for output in outputs {
+                let id = match &output {
+                    jsonrpc_core::Output::Success(success) => success.id.clone(),
+                    jsonrpc_core::Output::Failure(failure) => failure.id.clone(),
+                };
+                if let Some(callback) = pending_requests.remove(&id) {
+                    let _ = callback.send(Transport::output_into_result(output));
+                }
+            }
+            return Ok(())
+        }
+
+        if let Ok(notification) = serde_json::from_str::<jsonrpc_core::Notification>(&text) {
+            // CodeChain's pub/sub notifications carry `{subscription, result}`
+            // params under a method name of their own (e.g. `*_subscription`),
+            // distinct from the method used to start the subscription, so
+            // subscribers must be matched by subscription id, not by method.
+            let params = notification.params.and_then(|params| params.parse::<Value>().ok());
+            if let Some(Value::Object(params)) = params {
+                if let Some(subscription_id) = params.get("subscription") {
+                    let key = Transport::subscription_key(subscription_id);
+                    let result = params.get("result").cloned().unwrap_or(Value::Null);
+                    let subscriptions = self.subscriptions.lock().expect("Lock should not be poisoned");
+                    if let Some(senders) = subscriptions.get(&key) {
+                        for sender in senders {
+                            let _ = sender.send(result.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn on_close(&mut self, code: ws::CloseCode, reason: &str) {
+        self.alive.store(false, Ordering::SeqCst);
+        cinfo!("CodeChain RPC connection closed: {:?} {}", code, reason);
+    }
+
+    fn on_error(&mut self, err: ws::Error) {
+        self.alive.store(false, Ordering::SeqCst);
+        cwarn!("CodeChain RPC connection error: {}", err);
+    }
+}
+
+impl Transport {
+    /// Connects to `url`, retrying connection-refused/transport errors up to
+    /// `max_retries` times with exponential backoff (100ms, 200ms, 400ms...
+    /// capped at 3s).
+    pub fn connect(url: &str, max_retries: usize) -> Result<Self, Error> {
+        let mut backoff = Duration::from_millis(100);
+        let mut attempt = 0;
+        loop {
+            match Self::connect_once(url) {
+                Ok(transport) => return Ok(transport),
+                Err(err) => {
+                    if attempt >= max_retries {
+                        return Err(err)
+                    }
+                    cwarn!("Failed to connect to CodeChain RPC, retrying in {:?}: {:?}", backoff, err);
+                    thread::sleep(backoff);
+                    backoff = cmp::min(backoff * 2, Duration::from_secs(3));
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    fn connect_once(url: &str) -> Result<Self, Error> {
+        let pending_requests: PendingRequests = Arc::new(Mutex::new(HashMap::new()));
+        let subscriptions: Subscriptions = Arc::new(Mutex::new(HashMap::new()));
+        let alive = Arc::new(AtomicBool::new(true));
+        let (ready_tx, ready_rx) = channel();
+
+        let url = url.to_string();
+        let thread_pending_requests = pending_requests.clone();
+        let thread_subscriptions = subscriptions.clone();
+        let thread_alive = alive.clone();
+        thread::Builder::new()
+            .name("process-rpc-transport".to_string())
+            .spawn(move || {
+                let connect_result = ws::connect(url, |out| {
+                    let _ = ready_tx.send(out.clone());
+                    TransportHandler {
+                        out,
+                        pending_requests: thread_pending_requests.clone(),
+                        subscriptions: thread_subscriptions.clone(),
+                        alive: thread_alive.clone(),
+                    }
+                });
+                // The socket is no longer usable once `ws::connect` returns,
+                // whether or not `on_close`/`on_error` already flipped this.
+                thread_alive.store(false, Ordering::SeqCst);
+                if let Err(err) = connect_result {
+                    cerror!("CodeChain RPC transport closed unexpectedly: {}", err);
+                }
+            })
+            .expect("Should success running process-rpc-transport thread");
+
+        let out = ready_rx.recv().map_err(|err| Error::CodeChainRPC(format!("{}", err)))?;
+
+        Ok(Self {
+            out,
+            next_id: AtomicUsize::new(1),
+            pending_requests,
+            subscriptions,
+            alive,
+        })
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.alive.load(Ordering::SeqCst)
+    }
+
+    fn output_into_result(output: jsonrpc_core::Output) -> Result<Value, Error> {
+        match output {
+            jsonrpc_core::Output::Success(success) => Ok(success.result),
+            jsonrpc_core::Output::Failure(failure) => Err(Error::CodeChainRPC(format!("{:?}", failure.error))),
+        }
+    }
+
+    // Subscription ids come back from `_subscribe` as whatever JSON type the
+    // node uses (CodeChain sends a number), so key `subscriptions` off of
+    // their canonical JSON text rather than assuming a particular `Value`
+    // variant.
+    fn subscription_key(id: &Value) -> String {
+        id.to_string()
+    }
+
+    pub fn call(&self, method: String, arguments: Vec<Value>, timeout: Duration) -> Result<Value, Error> {
+        let id = jsonrpc_core::Id::Num(self.next_id.fetch_add(1, Ordering::SeqCst) as u64);
+        let (callback, response) = channel();
+        self.pending_requests.lock().expect("Lock should not be poisoned").insert(id.clone(), callback);
+
+        let call = jsonrpc_core::MethodCall {
+            jsonrpc: None,
+            method,
+            params: Some(jsonrpc_core::Params::Array(arguments)),
+            id: id.clone(),
+        };
+        let request = serde_json::to_string(&call).expect("Should success jsonrpc type to String");
+        if let Err(err) = self.out.send(request) {
+            // The callback registered above will never be fulfilled now;
+            // don't leak it in `pending_requests`.
+            self.pending_requests.lock().expect("Lock should not be poisoned").remove(&id);
+            return Err(Error::CodeChainRPC(format!("{}", err)))
+        }
+
+        self.recv_with_timeout(&id, response, timeout)
+    }
+
+    fn recv_with_timeout(
+        &self,
+        id: &jsonrpc_core::Id,
+        response: Receiver<Result<Value, Error>>,
+        timeout: Duration,
+    ) -> Result<Value, Error> {
+        match response.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => {
+                self.pending_requests.lock().expect("Lock should not be poisoned").remove(id);
+                Err(Error::RPCTimeout)
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                Err(Error::CodeChainRPC("CodeChain RPC transport closed".to_string()))
+            }
+        }
+    }
+
+    /// Sends many calls as a single JSON-RPC batch request. Each call still
+    /// waits on its own per-id channel, so the results come back in the
+    /// caller's input order even if the node's batch response is reordered.
+    pub fn call_batch(&self, calls: Vec<(String, Vec<Value>)>, timeout: Duration) -> Result<Vec<Value>, Error> {
+        let mut method_calls = Vec::with_capacity(calls.len());
+        let mut responses = Vec::with_capacity(calls.len());
+
+        {
+            let mut pending_requests = self.pending_requests.lock().expect("Lock should not be poisoned");
+            for (method, arguments) in calls {
+                let id = jsonrpc_core::Id::Num(self.next_id.fetch_add(1, Ordering::SeqCst) as u64);
+                let (callback, response) = channel();
+                pending_requests.insert(id.clone(), callback);
+                method_calls.push(jsonrpc_core::MethodCall {
+                    jsonrpc: None,
+                    method,
+                    params: Some(jsonrpc_core::Params::Array(arguments)),
+                    id: id.clone(),
+                });
+                responses.push((id, response));
+            }
+        }
+
+        let request = serde_json::to_string(&method_calls).expect("Should success jsonrpc type to String");
+        if let Err(err) = self.out.send(request) {
+            // None of the callbacks registered above will ever be fulfilled
+            // now; don't leak them in `pending_requests`.
+            let mut pending_requests = self.pending_requests.lock().expect("Lock should not be poisoned");
+            for (id, _) in &responses {
+                pending_requests.remove(id);
+            }
+            return Err(Error::CodeChainRPC(format!("{}", err)))
+        }
+
+        let mut results = Vec::with_capacity(responses.len());
+        let mut responses = responses.into_iter();
+        while let Some((id, response)) = responses.next() {
+            match self.recv_with_timeout(&id, response, timeout) {
+                Ok(result) => results.push(result),
+                Err(err) => {
+                    // Abandon the rest of the batch: drop their receivers
+                    // and remove their still-pending entries so they don't
+                    // leak in `pending_requests` for the transport's
+                    // lifetime.
+                    let mut pending_requests = self.pending_requests.lock().expect("Lock should not be poisoned");
+                    for (remaining_id, _) in responses {
+                        pending_requests.remove(&remaining_id);
+                    }
+                    return Err(err)
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    pub fn subscribe(&self, method: String, params: Vec<Value>, timeout: Duration) -> Result<Receiver<Value>, Error> {
+        // The `_subscribe` call's result is the subscription id CodeChain
+        // will tag every push notification with; register against that, not
+        // the method name, and only once the call actually succeeds so a
+        // failed subscribe doesn't leak a sender in `subscriptions` forever.
+        let subscription_id = self.call(method, params, timeout)?;
+        let key = Transport::subscription_key(&subscription_id);
+
+        let (sender, receiver) = channel();
+        self.subscriptions.lock().expect("Lock should not be poisoned").entry(key).or_insert_with(Vec::new).push(sender);
+
+        Ok(receiver)
+    }
 }
 
 pub enum Message {
@@ -61,23 +484,41 @@ pub enum Message {
         callback: Sender<Result<(), Error>>,
     },
     GetStatus {
-        callback: Sender<Result<NodeStatus, Error>>,
+        callback: Sender<Result<NodeStatusSnapshot, Error>>,
     },
     GetLog {
         callback: Sender<Result<String, Error>>,
     },
+    FollowLog {
+        callback: Sender<Receiver<String>>,
+    },
     CallRPC {
         method: String,
         arguments: Vec<Value>,
         callback: Sender<Result<Value, Error>>,
     },
+    CallRPCBatch {
+        calls: Vec<(String, Vec<Value>)>,
+        callback: Sender<Result<Vec<Value>, Error>>,
+    },
+    Subscribe {
+        method: String,
+        params: Vec<Value>,
+        callback: Sender<Result<Receiver<Value>, Error>>,
+    },
 }
 
 impl Process {
     pub fn run_thread(option: ProcessOption) -> Sender<Message> {
+        let rpc_port = option.rpc_port;
         let mut process = Self {
             option,
             child: None,
+            transport: None,
+            log_offset: Arc::new(Mutex::new(0)),
+            rpc_port,
+            chain_status: Arc::new(Mutex::new(None)),
+            poller: None,
         };
         let (tx, rx) = channel();
         thread::Builder::new()
@@ -102,6 +543,7 @@ impl Process {
                         Message::Quit {
                             callback,
                         } => {
+                            process.stop_poller();
                             callback.send(Ok(())).expect("Callback should be success");
                             break
                         }
@@ -113,7 +555,16 @@ impl Process {
                             } else {
                                 NodeStatus::Stop
                             };
-                            callback.send(Ok(status)).expect("Callback should be success");
+                            let chain_status = match status {
+                                NodeStatus::Run => process.chain_status.lock().expect("Lock should not be poisoned").clone(),
+                                _ => None,
+                            };
+                            callback
+                                .send(Ok(NodeStatusSnapshot {
+                                    status,
+                                    chain_status,
+                                }))
+                                .expect("Callback should be success");
                         }
                         Message::GetLog {
                             callback,
@@ -121,6 +572,12 @@ impl Process {
                             let result = process.get_log();
                             callback.send(result).expect("Callback should be success");
                         }
+                        Message::FollowLog {
+                            callback,
+                        } => {
+                            let receiver = process.follow_log();
+                            callback.send(receiver).expect("Callback should be success");
+                        }
                         Message::CallRPC {
                             method,
                             arguments,
@@ -129,6 +586,21 @@ impl Process {
                             let result = process.call_rpc(method, arguments);
                             callback.send(result).expect("Callback should be success")
                         }
+                        Message::CallRPCBatch {
+                            calls,
+                            callback,
+                        } => {
+                            let result = process.call_rpc_batch(calls);
+                            callback.send(result).expect("Callback should be success")
+                        }
+                        Message::Subscribe {
+                            method,
+                            params,
+                            callback,
+                        } => {
+                            let result = process.subscribe(method, params);
+                            callback.send(result).expect("Callback should be success")
+                        }
                     }
                 }
             })
@@ -144,15 +616,15 @@ impl Process {
         let args_iter = args.split_whitespace();
         let args_vec: Vec<String> = args_iter.map(|str| str.to_string()).collect();
 
+        self.rpc_port = Self::parse_rpc_port(&args_vec).unwrap_or(self.option.rpc_port);
+
         let envs = Self::parse_env(&env)?;
 
-        let mut exec = Exec::cmd("cargo")
-            .arg("run")
-            .arg("--")
-            .cwd(self.option.codechain_dir.clone())
-            .stdout(Redirection::Pipe)
-            .stderr(Redirection::Merge)
-            .args(&args_vec);
+        let mut exec = match &self.option.execution_mode {
+            ExecutionMode::CargoRun => Exec::cmd("cargo").arg("run").arg("--").cwd(self.option.codechain_dir.clone()),
+            ExecutionMode::Binary(binary_path) => Exec::cmd(binary_path).cwd(self.option.codechain_dir.clone()),
+        };
+        exec = exec.stdout(Redirection::Pipe).stderr(Redirection::Merge).args(&args_vec);
 
         for (k, v) in envs {
             exec = exec.env(k, v);
@@ -161,9 +633,31 @@ impl Process {
         let child = (exec | Exec::cmd("tee").arg(self.option.log_file_path.clone())).popen()?;
         self.child = Some(child);
 
+        self.spawn_poller();
+
         Ok(())
     }
 
+    fn spawn_poller(&mut self) {
+        self.stop_poller();
+
+        *self.chain_status.lock().expect("Lock should not be poisoned") = None;
+        let url = format!("ws://127.0.0.1:{}/", self.rpc_port);
+        self.poller = Some(Poller::spawn(
+            url,
+            self.option.rpc_max_retries,
+            self.option.rpc_timeout,
+            self.option.status_poll_interval,
+            self.chain_status.clone(),
+        ));
+    }
+
+    fn stop_poller(&mut self) {
+        if let Some(poller) = self.poller.take() {
+            poller.stop();
+        }
+    }
+
     pub fn is_running(&mut self) -> bool {
         if self.child.is_none() {
             return false
@@ -177,6 +671,25 @@ impl Process {
         }
     }
 
+    // Looks for a `--jsonrpc-port`/`--port` flag (as `--flag value` or
+    // `--flag=value`) among the launch args, so `call_rpc` can target the
+    // port CodeChain actually bound instead of always using `option.rpc_port`.
+    fn parse_rpc_port(args: &[String]) -> Option<u16> {
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            if arg == "--jsonrpc-port" || arg == "--port" {
+                return iter.next().and_then(|value| value.parse().ok())
+            }
+            if arg.starts_with("--jsonrpc-port=") {
+                return arg["--jsonrpc-port=".len()..].parse().ok()
+            }
+            if arg.starts_with("--port=") {
+                return arg["--port=".len()..].parse().ok()
+            }
+        }
+        None
+    }
+
     fn parse_env(env: &str) -> Result<Vec<(&str, &str)>, Error> {
         let env_kvs = env.split_whitespace();
         let mut ret = Vec::new();
@@ -196,6 +709,17 @@ impl Process {
             return Err(Error::NotRunning)
         }
 
+        // Signal CodeChain to exit before joining the poller thread below:
+        // the poller can block for up to `rpc_timeout` inside a call, and
+        // the node should not have to wait for that just to receive SIGTERM.
+        let result = self.terminate_child();
+
+        self.stop_poller();
+
+        result
+    }
+
+    fn terminate_child(&mut self) -> Result<(), Error> {
         let codechain = &mut self.child.as_mut().expect("Already checked")[0];
         ctrace!("Send SIGTERM to CodeChain");
         codechain.terminate()?;
@@ -219,31 +743,113 @@ impl Process {
         let mut file = File::open(file_name)?;
         let mut contents = String::new();
         file.read_to_string(&mut contents)?;
+
+        *self.log_offset.lock().expect("Lock should not be poisoned") = contents.len() as u64;
+
         Ok(contents)
     }
 
-    fn call_rpc(&mut self, method: String, arguments: Vec<Value>) -> Result<Value, Error> {
-        // FIXME: Get port number from args
-        let port = 8080;
+    fn follow_log(&mut self) -> Receiver<String> {
+        const POLL_INTERVAL: Duration = Duration::from_millis(500);
 
-        let params = jsonrpc_core::Params::Array(arguments);
+        let file_name = self.option.log_file_path.clone();
+        // Start from wherever `GetLog` last left off, then track progress in
+        // a local variable from here on: this follower owns it exclusively,
+        // so it can't race with `GetLog` or another concurrent follower.
+        let mut offset = *self.log_offset.lock().expect("Lock should not be poisoned");
+        let (tx, rx) = channel();
 
-        let jsonrpc_request = jsonrpc_core::MethodCall {
-            jsonrpc: None,
-            method,
-            params: Some(params),
-            id: jsonrpc_core::Id::Num(1),
-        };
+        thread::Builder::new()
+            .name("process-log-follower".to_string())
+            .spawn(move || {
+                let mut carry = Vec::new();
+                loop {
+                    match Self::read_new_log_lines(&file_name, &mut offset, &mut carry) {
+                        Ok(lines) => {
+                            for line in lines {
+                                if tx.send(line).is_err() {
+                                    // No one is listening anymore.
+                                    return
+                                }
+                            }
+                        }
+                        Err(err) => {
+                            cwarn!("Failed to follow CodeChain log: {:?}", err);
+                        }
+                    }
+                    thread::sleep(POLL_INTERVAL);
+                }
+            })
+            .expect("Should success running process-log-follower thread");
 
-        let url = format!("http://127.0.0.1:{}/", port);
-        let client = reqwest::Client::new();
-        let mut response =
-            client.get(&url).json(&jsonrpc_request).send().map_err(|err| Error::CodeChainRPC(format!("{}", err)))?;
+        rx
+    }
+
+    fn read_new_log_lines(file_name: &str, offset: &mut u64, carry: &mut Vec<u8>) -> Result<Vec<String>, Error> {
+        const CHUNK_SIZE: usize = 64 * 1024;
+
+        let mut file = File::open(file_name)?;
+        let file_len = file.metadata()?.len();
+
+        if file_len < *offset {
+            // The log file shrank, which means it was truncated or rotated
+            // under us; start tailing again from the beginning.
+            *offset = 0;
+            carry.clear();
+        }
+
+        file.seek(SeekFrom::Start(*offset))?;
+
+        let mut lines = Vec::new();
+        let mut buf = [0u8; CHUNK_SIZE];
+        loop {
+            let read = file.read(&mut buf)?;
+            if read == 0 {
+                break
+            }
+            // Buffer raw bytes across reads and only split on `\n` here; a
+            // multi-byte UTF-8 character can straddle a chunk boundary, and
+            // decoding each chunk independently (e.g. with
+            // `from_utf8_lossy`) would corrupt it into U+FFFD. `\n` is
+            // 0x0A, which never occurs as part of a multi-byte UTF-8
+            // sequence, so splitting on raw bytes is safe.
+            carry.extend_from_slice(&buf[..read]);
+            *offset += read as u64;
+
+            while let Some(index) = carry.iter().position(|&byte| byte == b'\n') {
+                let line: Vec<u8> = carry.drain(..=index).collect();
+                lines.push(String::from_utf8_lossy(&line[..line.len() - 1]).into_owned());
+            }
+        }
 
-        let response: jsonrpc_core::Response =
-            response.json().map_err(|err| Error::CodeChainRPC(format!("JSON parse failed {}", err)))?;
-        let value = serde_json::to_value(response).expect("Should success jsonrpc type to Value");
+        Ok(lines)
+    }
+
+    fn ensure_transport(&mut self) -> Result<&Transport, Error> {
+        let needs_reconnect = self.transport.as_ref().map_or(true, |transport| !transport.is_alive());
+        if needs_reconnect {
+            let url = format!("ws://127.0.0.1:{}/", self.rpc_port);
+            self.transport = Some(Transport::connect(&url, self.option.rpc_max_retries)?);
+        }
+
+        Ok(self.transport.as_ref().expect("Just ensured it is Some"))
+    }
+
+    fn call_rpc(&mut self, method: String, arguments: Vec<Value>) -> Result<Value, Error> {
+        let timeout = self.option.rpc_timeout;
+        let transport = self.ensure_transport()?;
+        transport.call(method, arguments, timeout)
+    }
+
+    fn call_rpc_batch(&mut self, calls: Vec<(String, Vec<Value>)>) -> Result<Vec<Value>, Error> {
+        let timeout = self.option.rpc_timeout;
+        let transport = self.ensure_transport()?;
+        transport.call_batch(calls, timeout)
+    }
 
-        Ok(value)
+    fn subscribe(&mut self, method: String, params: Vec<Value>) -> Result<Receiver<Value>, Error> {
+        let timeout = self.option.rpc_timeout;
+        let transport = self.ensure_transport()?;
+        transport.subscribe(method, params, timeout)
     }
 }